@@ -0,0 +1,185 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use rgb_lib::bitcoin::PublicKey;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use thunder_swap::{
+    AssetAmount, AtomicRgbLnLiquidityProvider, PendingPaymentPolicy, RecipientKind, RgbLnInvoice,
+    SwapCompletion, ThunderSwapConfig,
+};
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "thunder-swap",
+    about = "RGB <-> Lightning atomic swap liquidity provider"
+)]
+struct Cli {
+    /// TOML config file; THUNDER_SWAP_* env vars override its values
+    #[arg(long, env = "THUNDER_SWAP_CONFIG")]
+    config: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// What `claim` does while the LN payment is pending.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Pending {
+    /// Poll the payment for up to --pending-timeout seconds
+    Wait,
+    /// Exit leaving the swap in progress; re-run claim to finish it
+    Return,
+    /// Fail right away
+    Fail,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Create an HTLC swap for an RGB-LN invoice and print the offer
+    CreateSwap {
+        #[arg(long)]
+        payment_hash: String,
+        #[arg(long)]
+        amount: u64,
+        #[arg(long)]
+        asset_id: String,
+        #[arg(long)]
+        user_pubkey: String,
+        #[arg(long, default_value = "")]
+        description: String,
+        #[arg(long, default_value_t = 3600)]
+        expiry: u64,
+        /// Key the swap by this id instead of sha256(payment_hash)
+        #[arg(long)]
+        external_id: Option<String>,
+        /// Hex ECDSA signature by user_pubkey over the swap authorization digest
+        #[arg(long)]
+        user_signature: Option<String>,
+        /// RGB receive type: witness-script (HTLC) or blinded
+        #[arg(long, default_value = "witness-script")]
+        recipient_kind: String,
+    },
+    /// Refresh the wallet and report whether the HTLC is funded
+    CheckFunding {
+        #[arg(long)]
+        swap_id: String,
+    },
+    /// Pay the LN invoice and claim the HTLC with the revealed preimage
+    Claim {
+        #[arg(long)]
+        swap_id: String,
+        #[arg(long)]
+        invoice: String,
+        #[arg(long, value_enum, default_value = "wait")]
+        pending: Pending,
+        #[arg(long, default_value_t = 60)]
+        pending_timeout: u64,
+    },
+    /// Print the data the user needs to refund after the timelock
+    Refund {
+        #[arg(long)]
+        swap_id: String,
+    },
+    /// Print the current status of a swap
+    Status {
+        #[arg(long)]
+        swap_id: String,
+    },
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(value)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    let config = ThunderSwapConfig::load(cli.config.as_deref())?;
+    let mut lp = AtomicRgbLnLiquidityProvider::from_config(&config)?;
+
+    match cli.command {
+        Command::CreateSwap {
+            payment_hash,
+            amount,
+            asset_id,
+            user_pubkey,
+            description,
+            expiry,
+            external_id,
+            user_signature,
+            recipient_kind,
+        } => {
+            let user_pubkey = PublicKey::from_str(&user_pubkey)?;
+            let invoice = RgbLnInvoice::new(
+                &payment_hash,
+                AssetAmount::new(asset_id, amount),
+                description,
+                expiry,
+            )?;
+            let offer = lp.create_atomic_swap(
+                invoice,
+                user_pubkey,
+                external_id,
+                user_signature,
+                RecipientKind::from_str(&recipient_kind)?,
+            )?;
+            println!("{}", to_json(&offer)?);
+        }
+        Command::CheckFunding { swap_id } => {
+            #[cfg(any(feature = "electrum", feature = "esplora"))]
+            {
+                let online = lp.go_online(false, config.indexer_url.clone())?;
+                let status = lp.check_htlc_funding(online, &swap_id)?;
+                println!("{:?}", status);
+            }
+            #[cfg(not(any(feature = "electrum", feature = "esplora")))]
+            {
+                let _ = swap_id;
+                return Err("check-funding requires the electrum or esplora feature".into());
+            }
+        }
+        Command::Claim {
+            swap_id,
+            invoice,
+            pending,
+            pending_timeout,
+        } => {
+            let policy = match pending {
+                Pending::Wait => PendingPaymentPolicy::WaitAndRetry {
+                    timeout: Duration::from_secs(pending_timeout),
+                },
+                Pending::Return => PendingPaymentPolicy::ReturnInProgress,
+                Pending::Fail => PendingPaymentPolicy::Fail,
+            };
+            // The claim is signed and broadcast online; going online first
+            // keeps complete_atomic_swap from refusing before it pays.
+            #[cfg(any(feature = "electrum", feature = "esplora"))]
+            lp.go_online(false, config.indexer_url.clone())?;
+            match lp.complete_atomic_swap(&swap_id, &invoice, policy)? {
+                SwapCompletion::Claimed(result) => println!("{}", to_json(&result)?),
+                SwapCompletion::InProgress { swap_id } => {
+                    println!(
+                        "Payment for swap {} is still pending, re-run claim to finish it",
+                        swap_id
+                    )
+                }
+                SwapCompletion::Settled { swap_id, .. } => {
+                    println!(
+                        "Swap {} settled, the asset is already in the wallet",
+                        swap_id
+                    )
+                }
+            }
+        }
+        Command::Refund { swap_id } => {
+            let info = lp.get_refund_info(&swap_id)?;
+            println!("{}", to_json(&info)?);
+        }
+        Command::Status { swap_id } => {
+            let status = lp.get_swap_status(&swap_id)?;
+            println!("{:?}", status);
+        }
+    }
+
+    Ok(())
+}